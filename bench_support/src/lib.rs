@@ -0,0 +1,14 @@
+//! Shared fixtures used across this lab's benchmark crates, kept in one
+//! place so the size tables can't drift between benches that are meant to
+//! be compared side by side.
+
+/// Fixture lengths chosen to straddle the inline/heap boundary that
+/// `SmallString` and friends use: empty, a single byte, a short inline
+/// string (15), `SmallString`'s inline capacity itself (22), just over it
+/// (23, 24), a small heap string (64), and a large heap string (500).
+pub const FIXTURE_SIZES: &[usize] = &[0, 1, 15, 22, 23, 24, 64, 500];
+
+/// Builds a fixture string of the given length.
+pub fn fixture(size: usize) -> String {
+    "a".repeat(size)
+}
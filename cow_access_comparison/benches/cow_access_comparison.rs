@@ -0,0 +1,58 @@
+use bench_support::{fixture, FIXTURE_SIZES};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::borrow::Cow;
+use std::rc::Rc;
+
+/// Thin wrapper around `Cow<'static, str>`, used here only to measure the
+/// per-access overhead a Cow-backed string pays versus a plain `&str`.
+pub struct CowStr(Cow<'static, str>);
+
+impl CowStr {
+    pub fn owned(s: String) -> Self {
+        CowStr(Cow::Owned(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// `.bytes().count()` is the point here, not `.len()` — this benchmark
+// measures the cost of actually iterating the bytes, not just reading a
+// cached length.
+#[allow(clippy::bytes_count_to_len)]
+fn access(s: &str) -> (usize, bool) {
+    let byte_count = s.bytes().count();
+    let starts = s.starts_with('a');
+    (byte_count, starts)
+}
+
+fn bench_access(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Access Overhead");
+
+    for &size in FIXTURE_SIZES {
+        let owned = fixture(size);
+        let borrowed: &str = owned.as_str();
+        let rc_data = Rc::new(owned.clone());
+        let cow_data = CowStr::owned(owned.clone());
+
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("&str", size), &borrowed, |b, s| {
+            b.iter(|| black_box(access(black_box(s))));
+        });
+
+        group.bench_with_input(BenchmarkId::new("Rc<String>", size), &rc_data, |b, rc| {
+            b.iter(|| black_box(access(black_box(rc.as_str()))));
+        });
+
+        group.bench_with_input(BenchmarkId::new("CowStr", size), &cow_data, |b, cow| {
+            b.iter(|| black_box(access(black_box(cow.as_str()))));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_access);
+criterion_main!(benches);
@@ -0,0 +1,123 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::Rng;
+
+/// Two-phase in-place dedup. The first phase only reads, advancing until it
+/// finds the first adjacent duplicate; if none exists the vector is
+/// returned untouched with zero writes. Only once a duplicate is found does
+/// the second phase compact the rest of the vector in place.
+pub fn dedup_fast<T: PartialEq>(v: &mut Vec<T>) {
+    let len = v.len();
+    if len < 2 {
+        return;
+    }
+
+    let mut first_dup = None;
+    for i in 1..len {
+        if v[i] == v[i - 1] {
+            first_dup = Some(i);
+            break;
+        }
+    }
+
+    let Some(start) = first_dup else {
+        return;
+    };
+
+    let mut write = start;
+    for read in start..len {
+        if v[read] != v[write - 1] {
+            v.swap(write, read);
+            write += 1;
+        }
+    }
+
+    v.truncate(write);
+}
+
+/// Naive baseline: writes a (possibly identical) element on every step,
+/// the way a `retain`-style dedup does.
+fn naive_dedup<T: PartialEq + Clone>(v: &mut Vec<T>) {
+    let mut write = 0;
+    for read in 0..v.len() {
+        let keep = read == 0 || v[read] != v[write - 1];
+        v[write] = v[read].clone();
+        if keep {
+            write += 1;
+        }
+    }
+    v.truncate(write);
+}
+
+fn all_unique(size: usize) -> Vec<u32> {
+    (0..size as u32).collect()
+}
+
+fn all_duplicate(size: usize) -> Vec<u32> {
+    vec![7; size]
+}
+
+fn random_with_duplicates(size: usize) -> Vec<u32> {
+    let mut rng = rand::thread_rng();
+    let mut v = Vec::with_capacity(size);
+    let mut last = 0u32;
+    for _ in 0..size {
+        if rng.gen_range(0..4) == 0 {
+            v.push(last);
+        } else {
+            last = rng.gen_range(0..1000);
+            v.push(last);
+        }
+    }
+    v
+}
+
+const SIZES: &[usize] = &[100, 1_000, 100_000];
+
+type Fixture = fn(usize) -> Vec<u32>;
+
+fn bench_dedup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Dedup");
+
+    for &size in SIZES {
+        group.throughput(Throughput::Elements(size as u64));
+
+        let cases: [(&str, Fixture); 3] = [
+            ("all-unique", all_unique),
+            ("all-duplicate", all_duplicate),
+            ("random", random_with_duplicates),
+        ];
+
+        for (label, make) in cases {
+            let data = make(size);
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("dedup_fast/{label}"), size),
+                &data,
+                |b, data| {
+                    b.iter_batched(
+                        || data.clone(),
+                        |mut v| dedup_fast(black_box(&mut v)),
+                        criterion::BatchSize::SmallInput,
+                    );
+                },
+            );
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("naive_dedup/{label}"), size),
+                &data,
+                |b, data| {
+                    b.iter_batched(
+                        || data.clone(),
+                        |mut v| naive_dedup(black_box(&mut v)),
+                        criterion::BatchSize::SmallInput,
+                    );
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dedup);
+criterion_main!(benches);
@@ -0,0 +1,265 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Rough heap footprint of a value, used by `LruMemCache` to decide when to
+/// evict rather than counting entries.
+pub trait EstimatedSize {
+    fn estimated_size(&self) -> usize;
+}
+
+impl EstimatedSize for String {
+    fn estimated_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.capacity()
+    }
+}
+
+impl EstimatedSize for Vec<u8> {
+    fn estimated_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.capacity()
+    }
+}
+
+/// LRU cache that evicts by total estimated memory footprint of its keys and
+/// values, rather than by entry count.
+pub struct LruMemCache<K, V> {
+    map: HashMap<K, V>,
+    order: VecDeque<K>, // front = least recently used, back = most recently used
+    max_bytes: usize,
+    used_bytes: usize,
+}
+
+impl<K, V> LruMemCache<K, V>
+where
+    K: Eq + Hash + Clone + EstimatedSize,
+    V: EstimatedSize,
+{
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            map: HashMap::new(),
+            order: VecDeque::new(),
+            max_bytes,
+            used_bytes: 0,
+        }
+    }
+
+    fn entry_size(key: &K, value: &V) -> usize {
+        key.estimated_size() + value.estimated_size()
+    }
+
+    fn touch(&mut self, key: &K) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            let k = self.order.remove(pos).unwrap();
+            self.order.push_back(k);
+        }
+    }
+
+    fn evict_until_within_budget(&mut self, protect: Option<&K>) {
+        while self.used_bytes > self.max_bytes {
+            let Some(oldest) = self.order.front().cloned() else {
+                break;
+            };
+            if Some(&oldest) == protect && self.order.len() == 1 {
+                break;
+            }
+            self.order.pop_front();
+            if let Some(v) = self.map.remove(&oldest) {
+                self.used_bytes -= Self::entry_size(&oldest, &v);
+            }
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some(old) = self.map.remove(&key) {
+            self.used_bytes -= Self::entry_size(&key, &old);
+            self.order.retain(|k| k != &key);
+        }
+
+        self.used_bytes += Self::entry_size(&key, &value);
+        self.map.insert(key.clone(), value);
+        self.order.push_back(key);
+
+        self.evict_until_within_budget(None);
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if self.map.contains_key(key) {
+            self.touch(key);
+        }
+        self.map.get(key)
+    }
+
+    pub fn mutate_with<F>(&mut self, key: &K, f: F)
+    where
+        F: FnOnce(&mut V),
+    {
+        let Some(value) = self.map.get_mut(key) else {
+            return;
+        };
+        let before = value.estimated_size();
+        f(value);
+        let after = value.estimated_size();
+        if after >= before {
+            self.used_bytes += after - before;
+        } else {
+            self.used_bytes -= before - after;
+        }
+
+        self.touch(key);
+        self.evict_until_within_budget(Some(key));
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Restores the keys touched during one benchmark iteration, so an
+/// eviction-triggering iteration doesn't permanently shrink the cache that
+/// the next iteration measures against.
+pub trait RestoreKeys<K, V> {
+    fn restore(self, cache: &mut LruMemCache<K, V>, make_value: impl Fn(&K) -> V);
+}
+
+impl<K, V> RestoreKeys<K, V> for K
+where
+    K: Eq + Hash + Clone + EstimatedSize,
+    V: EstimatedSize,
+{
+    fn restore(self, cache: &mut LruMemCache<K, V>, make_value: impl Fn(&K) -> V) {
+        if !cache.contains(&self) {
+            let value = make_value(&self);
+            cache.insert(self, value);
+        }
+    }
+}
+
+impl<K, V, const N: usize> RestoreKeys<K, V> for [K; N]
+where
+    K: Eq + Hash + Clone + EstimatedSize,
+    V: EstimatedSize,
+{
+    fn restore(self, cache: &mut LruMemCache<K, V>, make_value: impl Fn(&K) -> V) {
+        for key in self {
+            key.restore(cache, &make_value);
+        }
+    }
+}
+
+impl<K, V> RestoreKeys<K, V> for Vec<K>
+where
+    K: Eq + Hash + Clone + EstimatedSize,
+    V: EstimatedSize,
+{
+    fn restore(self, cache: &mut LruMemCache<K, V>, make_value: impl Fn(&K) -> V) {
+        for key in self {
+            key.restore(cache, &make_value);
+        }
+    }
+}
+
+const MAX_BYTES: usize = 4096;
+const ENTRY_BYTES: usize = 16;
+
+fn make_key(i: usize) -> String {
+    format!("key-{i}")
+}
+
+fn make_value() -> Vec<u8> {
+    vec![0u8; ENTRY_BYTES]
+}
+
+fn bench_insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LruMemCache Insert");
+
+    group.bench_function("plain insert (room to spare)", |b| {
+        let mut cache = LruMemCache::new(MAX_BYTES);
+        let mut i = 0usize;
+        b.iter(|| {
+            cache.insert(make_key(i % 4), make_value());
+            i += 1;
+            black_box(&cache);
+        });
+    });
+
+    // Fixed-width keys so every entry has about the same estimated size,
+    // and a budget sized to hold just the 4 seeded entries, so the timed
+    // insert always has to evict one of them to make room.
+    fn eject_key(i: usize) -> String {
+        format!("key-{i:06}")
+    }
+
+    let eject_budget = 4 * (eject_key(0).estimated_size() + make_value().estimated_size());
+
+    group.bench_function("ejecting insert", |b| {
+        b.iter_batched(
+            || {
+                let mut cache = LruMemCache::new(eject_budget);
+                for i in 0..4 {
+                    cache.insert(eject_key(i), make_value());
+                }
+                cache
+            },
+            |mut cache| {
+                cache.insert(eject_key(4), make_value());
+                black_box(&cache);
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.finish();
+}
+
+fn bench_mutate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("LruMemCache Mutate");
+
+    group.bench_function("mutate within budget", |b| {
+        let mut cache = LruMemCache::new(MAX_BYTES);
+        let key = make_key(0);
+        cache.insert(key.clone(), make_value());
+
+        b.iter(|| {
+            cache.mutate_with(&key, |v| {
+                v[0] = v[0].wrapping_add(1);
+            });
+            black_box(&cache);
+            key.clone().restore(&mut cache, |_| make_value());
+        });
+    });
+
+    group.bench_function("mutate triggers eviction", |b| {
+        let key = make_key(0);
+
+        b.iter_batched(
+            || {
+                let mut cache = LruMemCache::new(MAX_BYTES);
+                cache.insert(key.clone(), make_value());
+                for i in 1..4 {
+                    cache.insert(make_key(i), make_value());
+                }
+                cache
+            },
+            |mut cache| {
+                cache.mutate_with(&key, |v| {
+                    v.extend(std::iter::repeat_n(0u8, MAX_BYTES));
+                });
+                black_box(&cache);
+            },
+            BatchSize::SmallInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_insert, bench_mutate);
+criterion_main!(benches);
@@ -0,0 +1,59 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::SeedableRng;
+use rand_pcg::Pcg32;
+
+fn bench_shuffle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Shuffle");
+    let mut rng = Pcg32::seed_from_u64(42);
+
+    for &size in &[100usize, 1_000] {
+        let data: Vec<usize> = (0..size).collect();
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("shuffle", size), &data, |b, data| {
+            let mut buf = data.clone();
+            b.iter(|| {
+                buf.shuffle(&mut rng);
+                black_box(&buf);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_choose(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Choose");
+    let mut rng = Pcg32::seed_from_u64(42);
+    let data: Vec<usize> = (0..1_000).collect();
+
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("choose", |b| {
+        b.iter(|| black_box(data.choose(&mut rng)));
+    });
+
+    group.finish();
+}
+
+fn bench_choose_multiple(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Choose Multiple");
+    let mut rng = Pcg32::seed_from_u64(42);
+    let data: Vec<usize> = (0..1_000).collect();
+
+    for &k in &[10usize, 100] {
+        group.throughput(Throughput::Elements(k as u64));
+
+        group.bench_with_input(BenchmarkId::new("choose_multiple", k), &k, |b, &k| {
+            b.iter(|| {
+                let picked: Vec<_> = data.iter().choose_multiple(&mut rng, k);
+                black_box(picked);
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_shuffle, bench_choose, bench_choose_multiple);
+criterion_main!(benches);
@@ -0,0 +1,66 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const INLINE_CAPACITY: usize = 22;
+
+/// Small-string-optimized string: values up to `N` bytes live inline in a
+/// fixed-size buffer and clone via memcpy; longer values fall back to a
+/// heap-allocated `Box<str>`, just like `String::clone`.
+#[derive(Clone)]
+pub enum SmallString<const N: usize = INLINE_CAPACITY> {
+    Inline { len: u8, buf: [u8; N] },
+    Heap(Box<str>),
+}
+
+impl<const N: usize> SmallString<N> {
+    pub fn new(s: &str) -> Self {
+        if s.len() <= N {
+            let mut buf = [0u8; N];
+            buf[..s.len()].copy_from_slice(s.as_bytes());
+            SmallString::Inline {
+                len: s.len() as u8,
+                buf,
+            }
+        } else {
+            SmallString::Heap(Box::from(s))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            SmallString::Inline { len, buf } => {
+                std::str::from_utf8(&buf[..*len as usize]).unwrap()
+            }
+            SmallString::Heap(s) => s,
+        }
+    }
+}
+
+use bench_support::{fixture, FIXTURE_SIZES};
+
+fn bench_clones(c: &mut Criterion) {
+    let mut group = c.benchmark_group("String vs SmallString Clone");
+
+    for &size in FIXTURE_SIZES {
+        let data = fixture(size);
+        let small = SmallString::<INLINE_CAPACITY>::new(&data);
+
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("String Clone", size), &data, |b, data| {
+            b.iter(|| black_box(data).clone());
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("SmallString Clone", size),
+            &small,
+            |b, small| {
+                b.iter(|| black_box(small).clone());
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_clones);
+criterion_main!(benches);
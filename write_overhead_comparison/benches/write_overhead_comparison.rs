@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::io::Write;
+
+const FRAGMENTS: &[&str] = &["hello", "world", "criterion", "benchmark", "fragment"];
+const LITERAL: &str = "hello";
+const REPETITIONS: usize = 1_000;
+
+fn total_bytes() -> u64 {
+    let per_rep: usize = FRAGMENTS.iter().map(|s| s.len()).sum();
+    (per_rep * REPETITIONS) as u64
+}
+
+fn literal_bytes() -> u64 {
+    (LITERAL.len() * REPETITIONS) as u64
+}
+
+fn bench_writes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Write Overhead");
+
+    group.throughput(Throughput::Bytes(total_bytes()));
+    group.bench_function(BenchmarkId::new("extend_from_slice", REPETITIONS), |b| {
+        let mut buf = Vec::new();
+        b.iter(|| {
+            buf.clear();
+            for _ in 0..REPETITIONS {
+                for frag in FRAGMENTS {
+                    buf.extend_from_slice(frag.as_bytes());
+                }
+            }
+            black_box(&buf);
+        });
+    });
+
+    group.throughput(Throughput::Bytes(total_bytes()));
+    group.bench_function(BenchmarkId::new("write! with args", REPETITIONS), |b| {
+        let mut buf = Vec::new();
+        b.iter(|| {
+            buf.clear();
+            for _ in 0..REPETITIONS {
+                for frag in FRAGMENTS {
+                    write!(buf, "{}", frag).unwrap();
+                }
+            }
+            black_box(&buf);
+        });
+    });
+
+    group.throughput(Throughput::Bytes(literal_bytes()));
+    group.bench_function(BenchmarkId::new("write! literal", REPETITIONS), |b| {
+        let mut buf = Vec::new();
+        b.iter(|| {
+            buf.clear();
+            for _ in 0..REPETITIONS {
+                write!(buf, "hello").unwrap();
+            }
+            black_box(&buf);
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_writes);
+criterion_main!(benches);